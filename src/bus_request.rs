@@ -0,0 +1,38 @@
+use std::fmt;
+
+use crate::{receivers::request::Envelope, Bus, Message};
+
+/// Returned by [`Bus::request`] when no reply ever arrives: either the send
+/// itself failed (the handler's receiver is closed) or the handler's task
+/// dropped the reply sender before producing a result.
+#[derive(Debug)]
+pub struct HandlerDropped;
+
+impl fmt::Display for HandlerDropped {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "request reply channel dropped before the handler replied")
+    }
+}
+
+impl std::error::Error for HandlerDropped {}
+
+impl Bus {
+    /// Sends `msg` to its subscribed request handler and awaits the typed
+    /// reply, rather than firing the message and forgetting about the result.
+    pub async fn request<M, R>(&self, msg: M) -> Result<R, Box<dyn std::error::Error + Send + Sync>>
+    where
+        M: Message,
+        R: Send + 'static,
+    {
+        let (reply, rx) = futures::channel::oneshot::channel();
+
+        if self.send(Envelope { msg, reply }).await.is_err() {
+            return Err(Box::new(HandlerDropped));
+        }
+
+        match rx.await {
+            Ok(result) => result,
+            Err(_) => Err(Box::new(HandlerDropped)),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod sync;
+
+pub use sync::{BufferOrderedSync, BufferOrderedSyncSubscriber};
@@ -0,0 +1,235 @@
+use crate::{
+    receiver::ReceiverStats,
+    receivers::{mpsc, stats_stream::stats_stream},
+};
+use futures::{executor::block_on, Future, Stream, StreamExt};
+use std::{
+    any::TypeId,
+    marker::PhantomData,
+    pin::Pin,
+    sync::{atomic::Ordering, Arc},
+    task::{Context, Poll},
+};
+use tokio::sync::watch;
+
+use crate::{
+    builder::{ReceiverSubscriber, ReceiverSubscriberBuilder},
+    msgs,
+    receiver::{AnyReceiver, ReceiverTrait, SendError, TypedReceiver},
+    receivers::buffer_unordered::{BufferUnorderedConfig, BufferUnorderedStats},
+    Bus, Handler, Message, Untyped,
+};
+
+/// Order-preserving sibling of [`BufferUnorderedSync`](crate::receivers::buffer_unordered::BufferUnorderedSync):
+/// up to `max_parallel` handler invocations still run concurrently on the
+/// blocking pool, but their results are observed and forwarded in submission
+/// order rather than completion order.
+pub struct BufferOrderedSyncSubscriber<T, M>
+where
+    T: Handler<M> + 'static,
+    M: Message,
+{
+    cfg: BufferUnorderedConfig,
+    _m: PhantomData<(M, T)>,
+}
+
+impl<T, M> ReceiverSubscriber<T> for BufferOrderedSyncSubscriber<T, M>
+where
+    T: Handler<M> + 'static,
+    M: Message,
+{
+    fn subscribe(
+        self,
+    ) -> (
+        Arc<dyn ReceiverTrait>,
+        Box<
+            dyn FnOnce(Untyped) -> Box<dyn FnOnce(Bus) -> Pin<Box<dyn Future<Output = ()> + Send>>>,
+        >,
+    ) {
+        let cfg = self.cfg;
+        let (tx, rx) = mpsc::channel(cfg.buffer_size);
+        let (stats, stats_rx) = BufferUnorderedStats::new(&cfg, std::any::type_name::<M>());
+        let stats = Arc::new(stats);
+
+        let arc = Arc::new(BufferOrderedSync::<M> {
+            tx,
+            stats: stats.clone(),
+            stats_rx,
+        });
+
+        let poller = Box::new(move |ut| {
+            Box::new(move |bus| {
+                Box::pin(buffer_ordered_poller::<T, M>(rx, bus, ut, stats, cfg))
+                    as Pin<Box<dyn Future<Output = ()> + Send>>
+            }) as Box<dyn FnOnce(Bus) -> Pin<Box<dyn Future<Output = ()> + Send>>>
+        });
+
+        (arc, poller)
+    }
+}
+
+async fn buffer_ordered_poller<T, M>(
+    rx: mpsc::Receiver<M>,
+    bus: Bus,
+    ut: Untyped,
+    stats: Arc<BufferUnorderedStats>,
+    cfg: BufferUnorderedConfig,
+) where
+    T: Handler<M> + 'static,
+    M: Message,
+{
+    let ut = ut.downcast_sync::<T>().unwrap();
+    let name = std::any::type_name::<M>();
+
+    let mut x = rx
+        .map(|msg| {
+            stats.buffer.fetch_sub(1, Ordering::Relaxed);
+            stats.parallel.fetch_add(1, Ordering::Relaxed);
+            stats.publish(name);
+
+            let bus = bus.clone();
+            let ut = ut.clone();
+
+            tokio::task::spawn_blocking(move || {
+                block_on(ut.lock_read()).get_ref().handle(msg, &bus)
+            })
+        })
+        .buffered(cfg.max_parallel);
+
+    while let Some(err) = x.next().await {
+        stats.parallel.fetch_sub(1, Ordering::Relaxed);
+        stats.publish(name);
+
+        match err {
+            Ok(Err(err)) => {
+                let _ = bus.send(msgs::Error(Arc::new(err))).await;
+            }
+            _ => (),
+        }
+    }
+
+    let ut = ut.clone();
+    let bus_clone = bus.clone();
+    let res =
+        tokio::task::spawn_blocking(move || block_on(ut.lock_read()).get_ref().sync(&bus_clone))
+            .await;
+
+    match res {
+        Ok(Err(err)) => {
+            let _ = bus.send(msgs::Error(Arc::new(err))).await;
+        }
+        _ => (),
+    }
+
+    stats.publish_closed(name);
+
+    println!("[EXIT] BufferOrderedSync<{}>", name);
+}
+
+pub struct BufferOrderedSync<M: Message> {
+    tx: mpsc::Sender<M>,
+    stats: Arc<BufferUnorderedStats>,
+    stats_rx: watch::Receiver<ReceiverStats>,
+}
+
+impl<M: Message> BufferOrderedSync<M> {
+    /// Returns a stream of `ReceiverStats` snapshots, published after each
+    /// dequeue-and-spawn and each task completion, for building dashboards or
+    /// autoscaling triggers without busy-polling `stats()`. Enqueueing a
+    /// message does not publish a snapshot.
+    pub fn stats_stream(&self) -> impl Stream<Item = ReceiverStats> {
+        stats_stream(self.stats_rx.clone())
+    }
+}
+
+impl<T, M> ReceiverSubscriberBuilder<M, T> for BufferOrderedSync<M>
+where
+    T: Handler<M> + 'static,
+    M: Message,
+{
+    type Entry = BufferOrderedSyncSubscriber<T, M>;
+    type Config = BufferUnorderedConfig;
+
+    fn build(cfg: Self::Config) -> Self::Entry {
+        BufferOrderedSyncSubscriber {
+            cfg,
+            _m: Default::default(),
+        }
+    }
+}
+
+impl<M: Message> TypedReceiver<M> for BufferOrderedSync<M> {
+    fn poll_ready(&self, ctx: &mut Context<'_>) -> Poll<()> {
+        match self.tx.poll_ready(ctx) {
+            Poll::Ready(_) => Poll::Ready(()),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn try_send(&self, m: M) -> Result<(), SendError<M>> {
+        match self.tx.try_send(m) {
+            Ok(_) => {
+                self.stats.buffer.fetch_add(1, Ordering::Relaxed);
+
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl<M: Message> ReceiverTrait for BufferOrderedSync<M> {
+    fn typed(&self) -> AnyReceiver<'_> {
+        AnyReceiver::new(self)
+    }
+
+    fn type_id(&self) -> TypeId {
+        TypeId::of::<BufferOrderedSync<M>>()
+    }
+
+    fn stats(&self) -> ReceiverStats {
+        self.stats.snapshot(std::any::type_name::<M>())
+    }
+
+    fn close(&self) {
+        self.tx.close();
+    }
+
+    fn sync(&self) {
+        self.tx.flush();
+    }
+
+    fn poll_synchronized(&self, _ctx: &mut Context<'_>) -> Poll<()> {
+        Poll::Ready(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{stream, StreamExt};
+    use std::time::Duration;
+
+    /// The whole reason `buffer_ordered_poller` uses `.buffered()` instead of
+    /// `.buffer_unordered()` is that results are forwarded in submission
+    /// order even when a later task finishes first; exercise that directly
+    /// by staggering completion so index 0 finishes last.
+    #[tokio::test]
+    async fn buffered_preserves_submission_order_despite_out_of_order_completion() {
+        let delays = [
+            Duration::from_millis(30),
+            Duration::from_millis(10),
+            Duration::from_millis(0),
+        ];
+
+        let results: Vec<usize> = stream::iter(delays.into_iter().enumerate())
+            .map(|(i, delay)| async move {
+                tokio::time::sleep(delay).await;
+                i
+            })
+            .buffered(delays.len())
+            .collect()
+            .await;
+
+        assert_eq!(results, vec![0, 1, 2]);
+    }
+}
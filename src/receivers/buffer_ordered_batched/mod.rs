@@ -0,0 +1,3 @@
+mod sync;
+
+pub use sync::{BufferOrderedBatchedSync, BufferOrderedBatchedSyncSubscriber};
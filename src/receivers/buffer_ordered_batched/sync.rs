@@ -0,0 +1,156 @@
+use crate::{
+    receiver::ReceiverStats,
+    receivers::{mpsc, stats_stream::stats_stream},
+};
+use futures::{Future, Stream};
+use std::{
+    any::TypeId,
+    marker::PhantomData,
+    pin::Pin,
+    sync::{atomic::Ordering, Arc},
+    task::{Context, Poll},
+};
+use tokio::sync::watch;
+
+use crate::{
+    builder::{ReceiverSubscriber, ReceiverSubscriberBuilder},
+    receiver::{AnyReceiver, ReceiverTrait, SendError, TypedReceiver},
+    receivers::buffer_unordered_batched::{
+        batched_poller, BufferUnorderedBatchedConfig, BufferUnorderedBatchedStats,
+    },
+    BatchHandler, Bus, Message, Untyped,
+};
+
+/// Order-preserving sibling of
+/// [`BufferUnorderedBatchedSync`](crate::receivers::buffer_unordered_batched::BufferUnorderedBatchedSync):
+/// batches are still formed the same way, but up to `max_parallel` batches
+/// run concurrently on the blocking pool with their results forwarded in
+/// submission order rather than completion order.
+pub struct BufferOrderedBatchedSyncSubscriber<T, M>
+where
+    T: BatchHandler<M> + 'static,
+    M: Message,
+{
+    cfg: BufferUnorderedBatchedConfig,
+    _m: PhantomData<(M, T)>,
+}
+
+impl<T, M> ReceiverSubscriber<T> for BufferOrderedBatchedSyncSubscriber<T, M>
+where
+    T: BatchHandler<M> + 'static,
+    M: Message,
+{
+    fn subscribe(
+        self,
+    ) -> (
+        Arc<dyn ReceiverTrait>,
+        Box<
+            dyn FnOnce(Untyped) -> Box<dyn FnOnce(Bus) -> Pin<Box<dyn Future<Output = ()> + Send>>>,
+        >,
+    ) {
+        let cfg = self.cfg;
+        let (tx, rx) = mpsc::channel(cfg.buffer_size);
+        let (stats, stats_rx) = BufferUnorderedBatchedStats::new(&cfg, std::any::type_name::<M>());
+        let stats = Arc::new(stats);
+
+        let arc = Arc::new(BufferOrderedBatchedSync::<M> {
+            tx,
+            stats: stats.clone(),
+            stats_rx,
+        });
+
+        let poller = Box::new(move |ut| {
+            Box::new(move |bus| {
+                Box::pin(batched_poller::<T, M>(
+                    rx,
+                    bus,
+                    ut,
+                    stats,
+                    cfg,
+                    true,
+                    "BufferOrderedBatchedSync",
+                )) as Pin<Box<dyn Future<Output = ()> + Send>>
+            }) as Box<dyn FnOnce(Bus) -> Pin<Box<dyn Future<Output = ()> + Send>>>
+        });
+
+        (arc, poller)
+    }
+}
+
+pub struct BufferOrderedBatchedSync<M: Message> {
+    tx: mpsc::Sender<M>,
+    stats: Arc<BufferUnorderedBatchedStats>,
+    stats_rx: watch::Receiver<ReceiverStats>,
+}
+
+impl<M: Message> BufferOrderedBatchedSync<M> {
+    /// Returns a stream of `ReceiverStats` snapshots, published after each
+    /// dequeue, batch dispatch, and batch completion, for building dashboards
+    /// or autoscaling triggers without busy-polling `stats()`. Enqueueing a
+    /// message does not publish a snapshot.
+    pub fn stats_stream(&self) -> impl Stream<Item = ReceiverStats> {
+        stats_stream(self.stats_rx.clone())
+    }
+}
+
+impl<T, M> ReceiverSubscriberBuilder<M, T> for BufferOrderedBatchedSync<M>
+where
+    T: BatchHandler<M> + 'static,
+    M: Message,
+{
+    type Entry = BufferOrderedBatchedSyncSubscriber<T, M>;
+    type Config = BufferUnorderedBatchedConfig;
+
+    fn build(cfg: Self::Config) -> Self::Entry {
+        BufferOrderedBatchedSyncSubscriber {
+            cfg,
+            _m: Default::default(),
+        }
+    }
+}
+
+impl<M: Message> TypedReceiver<M> for BufferOrderedBatchedSync<M> {
+    fn poll_ready(&self, ctx: &mut Context<'_>) -> Poll<()> {
+        match self.tx.poll_ready(ctx) {
+            Poll::Ready(_) => Poll::Ready(()),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn try_send(&self, m: M) -> Result<(), SendError<M>> {
+        match self.tx.try_send(m) {
+            Ok(_) => {
+                self.stats.buffer.fetch_add(1, Ordering::Relaxed);
+
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl<M: Message> ReceiverTrait for BufferOrderedBatchedSync<M> {
+    fn typed(&self) -> AnyReceiver<'_> {
+        AnyReceiver::new(self)
+    }
+
+    fn type_id(&self) -> TypeId {
+        TypeId::of::<BufferOrderedBatchedSync<M>>()
+    }
+
+    fn stats(&self) -> ReceiverStats {
+        self.stats.snapshot(std::any::type_name::<M>())
+    }
+
+    fn close(&self) {
+        self.tx.close();
+    }
+
+    fn sync(&self) {
+        self.tx.flush();
+    }
+
+    fn poll_synchronized(&self, _ctx: &mut Context<'_>) -> Poll<()> {
+        Poll::Ready(())
+    }
+}
@@ -0,0 +1,96 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use tokio::sync::watch;
+
+use crate::receiver::ReceiverStats;
+
+mod sync;
+
+pub use sync::{BufferUnorderedSync, BufferUnorderedSyncSubscriber};
+
+#[derive(Debug, Clone, Copy)]
+pub struct BufferUnorderedConfig {
+    pub buffer_size: usize,
+    pub max_parallel: usize,
+}
+
+impl Default for BufferUnorderedConfig {
+    fn default() -> Self {
+        Self {
+            buffer_size: 1024,
+            max_parallel: 4,
+        }
+    }
+}
+
+pub struct BufferUnorderedStats {
+    pub buffer: AtomicU64,
+    pub buffer_total: AtomicU64,
+    pub parallel: AtomicU64,
+    pub parallel_total: AtomicU64,
+    stats_tx: watch::Sender<ReceiverStats>,
+}
+
+impl BufferUnorderedStats {
+    pub(crate) fn new(
+        cfg: &BufferUnorderedConfig,
+        name: &'static str,
+    ) -> (Self, watch::Receiver<ReceiverStats>) {
+        let buffer = AtomicU64::new(0);
+        let buffer_total = AtomicU64::new(cfg.buffer_size as _);
+        let parallel = AtomicU64::new(0);
+        let parallel_total = AtomicU64::new(cfg.max_parallel as _);
+
+        let (stats_tx, stats_rx) = watch::channel(Self::fields(
+            name,
+            buffer.load(Ordering::Relaxed),
+            buffer_total.load(Ordering::Relaxed),
+            parallel.load(Ordering::Relaxed),
+            parallel_total.load(Ordering::Relaxed),
+        ));
+
+        (
+            Self {
+                buffer,
+                buffer_total,
+                parallel,
+                parallel_total,
+                stats_tx,
+            },
+            stats_rx,
+        )
+    }
+
+    fn fields(name: &'static str, buffer: u64, buffer_total: u64, parallel: u64, parallel_total: u64) -> ReceiverStats {
+        ReceiverStats {
+            name: name.into(),
+            fields: vec![
+                ("buffer".into(), buffer),
+                ("buffer_total".into(), buffer_total),
+                ("parallel".into(), parallel),
+                ("parallel_total".into(), parallel_total),
+            ],
+        }
+    }
+
+    pub(crate) fn snapshot(&self, name: &'static str) -> ReceiverStats {
+        Self::fields(
+            name,
+            self.buffer.load(Ordering::SeqCst),
+            self.buffer_total.load(Ordering::SeqCst),
+            self.parallel.load(Ordering::SeqCst),
+            self.parallel_total.load(Ordering::SeqCst),
+        )
+    }
+
+    /// Publishes the current counters to `stats_stream()` subscribers.
+    /// Called after each dequeue, task spawn, and task completion.
+    pub(crate) fn publish(&self, name: &'static str) {
+        let _ = self.stats_tx.send(self.snapshot(name));
+    }
+
+    /// Publishes a zeroed snapshot so watchers can detect receiver shutdown.
+    pub(crate) fn publish_closed(&self, name: &'static str) {
+        let _ = self.stats_tx.send(Self::fields(name, 0, 0, 0, 0));
+    }
+}
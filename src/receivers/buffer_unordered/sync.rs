@@ -1,15 +1,19 @@
-use crate::{receiver::ReceiverStats, receivers::mpsc};
-use futures::{executor::block_on, Future, StreamExt};
+use crate::{
+    receiver::ReceiverStats,
+    receivers::{mpsc, stats_stream::stats_stream},
+};
+use futures::{executor::block_on, stream::FuturesUnordered, Future, Stream, StreamExt};
 use std::{
     any::TypeId,
     marker::PhantomData,
     pin::Pin,
     sync::{
-        atomic::{AtomicU64, Ordering},
+        atomic::{AtomicUsize, Ordering},
         Arc,
     },
     task::{Context, Poll},
 };
+use tokio::sync::{watch, Semaphore};
 
 use super::{BufferUnorderedConfig, BufferUnorderedStats};
 use crate::{
@@ -19,6 +23,41 @@ use crate::{
     Bus, Handler, Message, Untyped,
 };
 
+/// A live handle for dialing a receiver's handler concurrency up or down
+/// after it has already been subscribed.
+#[derive(Clone)]
+pub struct ParallelControl {
+    tx: watch::Sender<usize>,
+}
+
+impl ParallelControl {
+    pub fn set_parallel(&self, n: usize) {
+        let _ = self.tx.send(n);
+    }
+}
+
+/// Forgets `permit` instead of returning it to the semaphore when the
+/// operator has dialed concurrency down, so in-flight work is never
+/// cancelled and only future admission shrinks.
+fn release_permit(permit: tokio::sync::OwnedSemaphorePermit, to_forget: &AtomicUsize) {
+    loop {
+        let pending = to_forget.load(Ordering::Relaxed);
+
+        if pending == 0 {
+            drop(permit);
+            return;
+        }
+
+        if to_forget
+            .compare_exchange(pending, pending - 1, Ordering::Relaxed, Ordering::Relaxed)
+            .is_ok()
+        {
+            permit.forget();
+            return;
+        }
+    }
+}
+
 pub struct BufferUnorderedSyncSubscriber<T, M>
 where
     T: Handler<M> + 'static,
@@ -43,22 +82,24 @@ where
     ) {
         let cfg = self.cfg;
         let (tx, rx) = mpsc::channel(cfg.buffer_size);
-        let stats = Arc::new(BufferUnorderedStats {
-            buffer: AtomicU64::new(0),
-            buffer_total: AtomicU64::new(cfg.buffer_size as _),
-            parallel: AtomicU64::new(0),
-            parallel_total: AtomicU64::new(cfg.max_parallel as _),
-        });
+        let (stats, stats_rx) = BufferUnorderedStats::new(&cfg, std::any::type_name::<M>());
+        let stats = Arc::new(stats);
+
+        let semaphore = Arc::new(Semaphore::new(cfg.max_parallel));
+        let (parallel_tx, parallel_rx) = watch::channel(cfg.max_parallel);
 
         let arc = Arc::new(BufferUnorderedSync::<M> {
             tx,
             stats: stats.clone(),
+            control: ParallelControl { tx: parallel_tx },
+            stats_rx,
         });
 
         let poller = Box::new(move |ut| {
             Box::new(move |bus| {
-                Box::pin(buffer_unordered_poller::<T, M>(rx, bus, ut, stats, cfg))
-                    as Pin<Box<dyn Future<Output = ()> + Send>>
+                Box::pin(buffer_unordered_poller::<T, M>(
+                    rx, bus, ut, stats, semaphore, parallel_rx,
+                )) as Pin<Box<dyn Future<Output = ()> + Send>>
             }) as Box<dyn FnOnce(Bus) -> Pin<Box<dyn Future<Output = ()> + Send>>>
         });
 
@@ -67,39 +108,104 @@ where
 }
 
 async fn buffer_unordered_poller<T, M>(
-    rx: mpsc::Receiver<M>,
+    mut rx: mpsc::Receiver<M>,
     bus: Bus,
     ut: Untyped,
     stats: Arc<BufferUnorderedStats>,
-    cfg: BufferUnorderedConfig,
+    semaphore: Arc<Semaphore>,
+    mut parallel_rx: watch::Receiver<usize>,
 ) where
     T: Handler<M> + 'static,
     M: Message,
 {
     let ut = ut.downcast_sync::<T>().unwrap();
+    let to_forget = Arc::new(AtomicUsize::new(0));
+    let mut tasks = FuturesUnordered::new();
+    let mut rx_done = false;
+    // A message that has been pulled off `rx` but is still waiting for a
+    // permit; kept separate from the permit acquisition so a full semaphore
+    // never stalls draining `tasks` or observing `parallel_rx` changes.
+    let mut pending: Option<M> = None;
+    let name = std::any::type_name::<M>();
+
+    loop {
+        if rx_done && tasks.is_empty() && pending.is_none() {
+            break;
+        }
+
+        tokio::select! {
+            changed = parallel_rx.changed() => {
+                if changed.is_err() {
+                    continue;
+                }
 
-    let mut x = rx
-        .map(|msg| {
-            stats.buffer.fetch_sub(1, Ordering::Relaxed);
-            stats.parallel.fetch_add(1, Ordering::Relaxed);
+                let target = *parallel_rx.borrow() as u64;
+                let current = stats.parallel_total.swap(target, Ordering::Relaxed);
 
-            let bus = bus.clone();
-            let ut = ut.clone();
+                if target > current {
+                    semaphore.add_permits((target - current) as usize);
+                } else if target < current {
+                    let shrink_by = (current - target) as usize;
 
-            tokio::task::spawn_blocking(move || {
-                block_on(ut.lock_read()).get_ref().handle(msg, &bus)
-            })
-        })
-        .buffer_unordered(cfg.max_parallel);
+                    // Forget whatever idle permits are sitting in the pool right
+                    // now; anything still in flight gets forgotten as it returns.
+                    let mut forgotten = 0;
+                    while forgotten < shrink_by {
+                        match semaphore.clone().try_acquire_owned() {
+                            Ok(permit) => {
+                                permit.forget();
+                                forgotten += 1;
+                            }
+                            Err(_) => break,
+                        }
+                    }
 
-    while let Some(err) = x.next().await {
-        stats.parallel.fetch_sub(1, Ordering::Relaxed);
+                    to_forget.fetch_add(shrink_by - forgotten, Ordering::Relaxed);
+                }
+            }
 
-        match err {
-            Ok(Err(err)) => {
-                let _ = bus.send(msgs::Error(Arc::new(err))).await;
+            msg = rx.next(), if pending.is_none() && !rx_done => {
+                match msg {
+                    Some(msg) => {
+                        stats.buffer.fetch_sub(1, Ordering::Relaxed);
+                        stats.publish(name);
+                        pending = Some(msg);
+                    }
+                    None => {
+                        rx_done = true;
+                    }
+                }
+            }
+
+            permit = semaphore.clone().acquire_owned(), if pending.is_some() => {
+                let permit = permit.expect("parallelism semaphore should never be closed");
+                let msg = pending.take().expect("guarded by pending.is_some()");
+
+                stats.parallel.fetch_add(1, Ordering::Relaxed);
+                stats.publish(name);
+
+                let bus = bus.clone();
+                let ut = ut.clone();
+                let to_forget = to_forget.clone();
+
+                tasks.push(tokio::task::spawn_blocking(move || {
+                    let result = block_on(ut.lock_read()).get_ref().handle(msg, &bus);
+                    release_permit(permit, &to_forget);
+                    result
+                }));
+            }
+
+            Some(res) = tasks.next(), if !tasks.is_empty() => {
+                stats.parallel.fetch_sub(1, Ordering::Relaxed);
+                stats.publish(name);
+
+                match res {
+                    Ok(Err(err)) => {
+                        let _ = bus.send(msgs::Error(Arc::new(err))).await;
+                    }
+                    _ => (),
+                }
             }
-            _ => (),
         }
     }
 
@@ -116,12 +222,32 @@ async fn buffer_unordered_poller<T, M>(
         _ => (),
     }
 
-    println!("[EXIT] BufferUnorderedSync<{}>", std::any::type_name::<M>());
+    stats.publish_closed(name);
+
+    println!("[EXIT] BufferUnorderedSync<{}>", name);
 }
 
 pub struct BufferUnorderedSync<M: Message> {
     tx: mpsc::Sender<M>,
     stats: Arc<BufferUnorderedStats>,
+    control: ParallelControl,
+    stats_rx: watch::Receiver<ReceiverStats>,
+}
+
+impl<M: Message> BufferUnorderedSync<M> {
+    /// Returns a cloneable handle for dialing `parallel_total` up or down at
+    /// runtime without cancelling in-flight handler invocations.
+    pub fn parallel_control(&self) -> ParallelControl {
+        self.control.clone()
+    }
+
+    /// Returns a stream of `ReceiverStats` snapshots, published after each
+    /// dequeue, task spawn, and task completion, for building dashboards or
+    /// autoscaling triggers without busy-polling `stats()`. Enqueueing a
+    /// message does not publish a snapshot.
+    pub fn stats_stream(&self) -> impl Stream<Item = ReceiverStats> {
+        stats_stream(self.stats_rx.clone())
+    }
 }
 
 impl<T, M> ReceiverSubscriberBuilder<M, T> for BufferUnorderedSync<M>
@@ -170,24 +296,7 @@ impl<M: Message> ReceiverTrait for BufferUnorderedSync<M> {
     }
 
     fn stats(&self) -> ReceiverStats {
-        ReceiverStats {
-            name: std::any::type_name::<M>().into(),
-            fields: vec![
-                ("buffer".into(), self.stats.buffer.load(Ordering::SeqCst)),
-                (
-                    "buffer_total".into(),
-                    self.stats.buffer_total.load(Ordering::SeqCst),
-                ),
-                (
-                    "parallel".into(),
-                    self.stats.parallel.load(Ordering::SeqCst),
-                ),
-                (
-                    "parallel_total".into(),
-                    self.stats.parallel_total.load(Ordering::SeqCst),
-                ),
-            ],
-        }
+        self.stats.snapshot(std::any::type_name::<M>())
     }
 
     fn close(&self) {
@@ -202,3 +311,58 @@ impl<M: Message> ReceiverTrait for BufferUnorderedSync<M> {
         Poll::Ready(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Mirrors the shrink branch of the `parallel_rx.changed()` arm in
+    /// `buffer_unordered_poller`: dialing `parallel_total` down past the
+    /// number of permits currently checked out must not cancel the in-flight
+    /// tasks holding them. Instead the shortfall is recorded in `to_forget`
+    /// and each permit is forgotten, rather than returned, as its task
+    /// finishes.
+    #[test]
+    fn shrinking_past_in_flight_tasks_forgets_permits_on_release() {
+        let semaphore = Arc::new(Semaphore::new(2));
+        let to_forget = AtomicUsize::new(0);
+
+        // Two tasks are in flight, each holding one permit.
+        let permit1 = semaphore.clone().try_acquire_owned().unwrap();
+        let permit2 = semaphore.clone().try_acquire_owned().unwrap();
+        assert_eq!(semaphore.available_permits(), 0);
+
+        // Operator dials parallelism from 2 down to 0: no idle permits are
+        // available to forget immediately, so the whole shortfall is queued.
+        let shrink_by = 2usize;
+        let mut forgotten = 0;
+        while forgotten < shrink_by {
+            match semaphore.clone().try_acquire_owned() {
+                Ok(permit) => {
+                    permit.forget();
+                    forgotten += 1;
+                }
+                Err(_) => break,
+            }
+        }
+        to_forget.fetch_add(shrink_by - forgotten, Ordering::Relaxed);
+        assert_eq!(to_forget.load(Ordering::Relaxed), 2);
+
+        // As each in-flight task finishes, its permit is forgotten rather
+        // than returned to the pool until the queued shrink is satisfied.
+        release_permit(permit1, &to_forget);
+        assert_eq!(to_forget.load(Ordering::Relaxed), 1);
+        assert_eq!(semaphore.available_permits(), 0);
+
+        release_permit(permit2, &to_forget);
+        assert_eq!(to_forget.load(Ordering::Relaxed), 0);
+        assert_eq!(semaphore.available_permits(), 0);
+
+        // Capacity has permanently shrunk by 2: a permit released after the
+        // queued shrink is satisfied goes back to the pool as normal.
+        semaphore.add_permits(1);
+        let permit3 = semaphore.clone().try_acquire_owned().unwrap();
+        release_permit(permit3, &to_forget);
+        assert_eq!(semaphore.available_permits(), 1);
+    }
+}
@@ -0,0 +1,110 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures::Stream;
+use tokio::time::Sleep;
+
+/// Batches items from the underlying stream by count, but flushes whatever
+/// is buffered once `timeout` has elapsed since the first item of the batch
+/// arrived, so a partial batch never stalls a handler indefinitely under
+/// light load. Later items do not restart the timer.
+pub struct LingerChunks<S: Stream> {
+    stream: S,
+    batch_size: usize,
+    timeout: Duration,
+    buf: Vec<S::Item>,
+    sleep: Option<Pin<Box<Sleep>>>,
+}
+
+impl<S: Stream> LingerChunks<S> {
+    pub fn new(stream: S, batch_size: usize, timeout: Duration) -> Self {
+        Self {
+            stream,
+            batch_size,
+            timeout,
+            buf: Vec::with_capacity(batch_size),
+            sleep: None,
+        }
+    }
+}
+
+impl<S: Stream + Unpin> Stream for LingerChunks<S> {
+    type Item = Vec<S::Item>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match Pin::new(&mut self.stream).poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    self.buf.push(item);
+
+                    // Empty -> non-empty transition: arm the linger timer.
+                    if self.buf.len() == 1 {
+                        self.sleep = Some(Box::pin(tokio::time::sleep(self.timeout)));
+                    }
+
+                    if self.buf.len() >= self.batch_size {
+                        self.sleep = None;
+                        return Poll::Ready(Some(std::mem::take(&mut self.buf)));
+                    }
+                }
+                Poll::Ready(None) => {
+                    self.sleep = None;
+
+                    return if self.buf.is_empty() {
+                        Poll::Ready(None)
+                    } else {
+                        Poll::Ready(Some(std::mem::take(&mut self.buf)))
+                    };
+                }
+                Poll::Pending => break,
+            }
+        }
+
+        if let Some(sleep) = self.sleep.as_mut() {
+            if sleep.as_mut().poll(cx).is_ready() {
+                self.sleep = None;
+                return Poll::Ready(Some(std::mem::take(&mut self.buf)));
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{channel::mpsc, StreamExt};
+
+    #[tokio::test(start_paused = true)]
+    async fn flushes_partial_batch_after_timeout() {
+        let (mut tx, rx) = mpsc::unbounded();
+        let mut chunks = LingerChunks::new(rx, 4, Duration::from_millis(100));
+
+        tx.unbounded_send(1).unwrap();
+        tx.unbounded_send(2).unwrap();
+
+        // Fewer than `batch_size` items have arrived, so the linger timer
+        // hasn't fired yet and nothing should be ready.
+        assert!(futures::poll!(chunks.next()).is_pending());
+
+        tokio::time::advance(Duration::from_millis(100)).await;
+
+        assert_eq!(chunks.next().await, Some(vec![1, 2]));
+    }
+
+    #[tokio::test]
+    async fn flushes_full_batch_without_waiting_for_timeout() {
+        let (mut tx, rx) = mpsc::unbounded();
+        let mut chunks = LingerChunks::new(rx, 2, Duration::from_secs(60));
+
+        tx.unbounded_send(1).unwrap();
+        tx.unbounded_send(2).unwrap();
+
+        assert_eq!(chunks.next().await, Some(vec![1, 2]));
+    }
+}
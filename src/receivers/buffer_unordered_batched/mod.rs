@@ -0,0 +1,128 @@
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use tokio::sync::watch;
+
+use crate::receiver::ReceiverStats;
+
+pub(crate) mod linger_chunks;
+mod sync;
+
+pub use sync::{BufferUnorderedBatchedSync, BufferUnorderedBatchedSyncSubscriber};
+pub(crate) use sync::batched_poller;
+
+#[derive(Debug, Clone, Copy)]
+pub struct BufferUnorderedBatchedConfig {
+    pub buffer_size: usize,
+    pub max_parallel: usize,
+    pub batch_size: usize,
+    pub when_ready: bool,
+    pub batch_timeout: Option<Duration>,
+}
+
+impl Default for BufferUnorderedBatchedConfig {
+    fn default() -> Self {
+        Self {
+            buffer_size: 1024,
+            max_parallel: 4,
+            batch_size: 64,
+            when_ready: false,
+            batch_timeout: None,
+        }
+    }
+}
+
+pub struct BufferUnorderedBatchedStats {
+    pub buffer: AtomicU64,
+    pub buffer_total: AtomicU64,
+    pub parallel: AtomicU64,
+    pub parallel_total: AtomicU64,
+    pub batch: AtomicU64,
+    pub batch_size: AtomicU64,
+    stats_tx: watch::Sender<ReceiverStats>,
+}
+
+impl BufferUnorderedBatchedStats {
+    pub(crate) fn new(
+        cfg: &BufferUnorderedBatchedConfig,
+        name: &'static str,
+    ) -> (Self, watch::Receiver<ReceiverStats>) {
+        let buffer = AtomicU64::new(0);
+        let buffer_total = AtomicU64::new(cfg.buffer_size as _);
+        let parallel = AtomicU64::new(0);
+        let parallel_total = AtomicU64::new(cfg.max_parallel as _);
+        let batch = AtomicU64::new(0);
+        let batch_size = AtomicU64::new(cfg.batch_size as _);
+
+        let (stats_tx, stats_rx) = watch::channel(Self::fields(
+            name,
+            buffer.load(Ordering::Relaxed),
+            buffer_total.load(Ordering::Relaxed),
+            parallel.load(Ordering::Relaxed),
+            parallel_total.load(Ordering::Relaxed),
+            batch.load(Ordering::Relaxed),
+            batch_size.load(Ordering::Relaxed),
+        ));
+
+        (
+            Self {
+                buffer,
+                buffer_total,
+                parallel,
+                parallel_total,
+                batch,
+                batch_size,
+                stats_tx,
+            },
+            stats_rx,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn fields(
+        name: &'static str,
+        buffer: u64,
+        buffer_total: u64,
+        parallel: u64,
+        parallel_total: u64,
+        batch: u64,
+        batch_size: u64,
+    ) -> ReceiverStats {
+        ReceiverStats {
+            name: name.into(),
+            fields: vec![
+                ("buffer".into(), buffer),
+                ("buffer_total".into(), buffer_total),
+                ("parallel".into(), parallel),
+                ("parallel_total".into(), parallel_total),
+                ("batch".into(), batch),
+                ("batch_size".into(), batch_size),
+            ],
+        }
+    }
+
+    pub(crate) fn snapshot(&self, name: &'static str) -> ReceiverStats {
+        Self::fields(
+            name,
+            self.buffer.load(Ordering::SeqCst),
+            self.buffer_total.load(Ordering::SeqCst),
+            self.parallel.load(Ordering::SeqCst),
+            self.parallel_total.load(Ordering::SeqCst),
+            self.batch.load(Ordering::SeqCst),
+            self.batch_size.load(Ordering::SeqCst),
+        )
+    }
+
+    /// Publishes the current counters to `stats_stream()` subscribers.
+    /// Called after each dequeue, task spawn, and task completion.
+    pub(crate) fn publish(&self, name: &'static str) {
+        let _ = self.stats_tx.send(self.snapshot(name));
+    }
+
+    /// Publishes a zeroed snapshot so watchers can detect receiver shutdown.
+    pub(crate) fn publish_closed(&self, name: &'static str) {
+        let _ = self.stats_tx.send(Self::fields(name, 0, 0, 0, 0, 0, 0));
+    }
+}
@@ -1,17 +1,18 @@
-use crate::{receiver::ReceiverStats, receivers::mpsc};
-use futures::{executor::block_on, Future, StreamExt};
+use crate::{
+    receiver::ReceiverStats,
+    receivers::{mpsc, stats_stream::stats_stream},
+};
+use futures::{executor::block_on, Future, Stream, StreamExt};
 use std::{
     any::TypeId,
     marker::PhantomData,
     pin::Pin,
-    sync::{
-        atomic::{AtomicU64, Ordering},
-        Arc,
-    },
+    sync::{atomic::Ordering, Arc},
     task::{Context, Poll},
 };
+use tokio::sync::watch;
 
-use super::{BufferUnorderedBatchedConfig, BufferUnorderedBatchedStats};
+use super::{linger_chunks::LingerChunks, BufferUnorderedBatchedConfig, BufferUnorderedBatchedStats};
 use crate::{
     builder::{ReceiverSubscriber, ReceiverSubscriberBuilder},
     msgs,
@@ -43,24 +44,26 @@ where
     ) {
         let cfg = self.cfg;
         let (tx, rx) = mpsc::channel(cfg.buffer_size);
-        let stats = Arc::new(BufferUnorderedBatchedStats {
-            buffer: AtomicU64::new(0),
-            buffer_total: AtomicU64::new(cfg.buffer_size as _),
-            parallel: AtomicU64::new(0),
-            parallel_total: AtomicU64::new(cfg.max_parallel as _),
-            batch: AtomicU64::new(0),
-            batch_size: AtomicU64::new(cfg.batch_size as _),
-        });
+        let (stats, stats_rx) = BufferUnorderedBatchedStats::new(&cfg, std::any::type_name::<M>());
+        let stats = Arc::new(stats);
 
         let arc = Arc::new(BufferUnorderedBatchedSync::<M> {
             tx,
             stats: stats.clone(),
+            stats_rx,
         });
 
         let poller = Box::new(move |ut| {
             Box::new(move |bus| {
-                Box::pin(buffer_unordered_poller::<T, M>(rx, bus, ut, stats, cfg))
-                    as Pin<Box<dyn Future<Output = ()> + Send>>
+                Box::pin(batched_poller::<T, M>(
+                    rx,
+                    bus,
+                    ut,
+                    stats,
+                    cfg,
+                    false,
+                    "BufferUnorderedBatchedSync",
+                )) as Pin<Box<dyn Future<Output = ()> + Send>>
             }) as Box<dyn FnOnce(Bus) -> Pin<Box<dyn Future<Output = ()> + Send>>>
         });
 
@@ -68,44 +71,64 @@ where
     }
 }
 
-async fn buffer_unordered_poller<T, M>(
+/// Shared batching/dispatch loop for [`BufferUnorderedBatchedSync`] and its
+/// order-preserving sibling
+/// [`BufferOrderedBatchedSync`](crate::receivers::buffer_ordered_batched::BufferOrderedBatchedSync):
+/// both form batches identically and differ only in whether completed
+/// batches are forwarded via `buffer_unordered` (`ordered = false`) or
+/// `buffered` (`ordered = true`). `receiver_name` is only used for the exit
+/// log line.
+pub(crate) async fn batched_poller<T, M>(
     rx: mpsc::Receiver<M>,
     bus: Bus,
     ut: Untyped,
     stats: Arc<BufferUnorderedBatchedStats>,
     cfg: BufferUnorderedBatchedConfig,
+    ordered: bool,
+    receiver_name: &'static str,
 ) where
     T: BatchHandler<M> + 'static,
     M: Message,
 {
     let ut = ut.downcast_sync::<T>().unwrap();
+    let name = std::any::type_name::<M>();
     let rx = rx.inspect(|_| {
         stats.buffer.fetch_sub(1, Ordering::Relaxed);
         stats.batch.fetch_add(1, Ordering::Relaxed);
+        stats.publish(name);
     });
 
-    let rx = if cfg.when_ready {
-        rx.ready_chunks(cfg.batch_size).left_stream()
+    let rx = if let Some(timeout) = cfg.batch_timeout {
+        LingerChunks::new(rx, cfg.batch_size, timeout).left_stream()
     } else {
-        rx.chunks(cfg.batch_size).right_stream()
+        (if cfg.when_ready {
+            rx.ready_chunks(cfg.batch_size).left_stream()
+        } else {
+            rx.chunks(cfg.batch_size).right_stream()
+        })
+        .right_stream()
     };
 
-    let mut rx = rx
-        .map(|msgs| {
-            stats.batch.fetch_sub(msgs.len() as _, Ordering::Relaxed);
-            stats.parallel.fetch_add(1, Ordering::Relaxed);
+    let rx = rx.map(|msgs| {
+        stats.batch.fetch_sub(msgs.len() as _, Ordering::Relaxed);
+        stats.parallel.fetch_add(1, Ordering::Relaxed);
+        stats.publish(name);
 
-            let bus = bus.clone();
-            let ut = ut.clone();
+        let bus = bus.clone();
+        let ut = ut.clone();
 
-            tokio::task::spawn_blocking(move || {
-                block_on(ut.lock_read()).get_ref().handle(msgs, &bus)
-            })
-        })
-        .buffer_unordered(cfg.max_parallel);
+        tokio::task::spawn_blocking(move || block_on(ut.lock_read()).get_ref().handle(msgs, &bus))
+    });
+
+    let mut rx = if ordered {
+        rx.buffered(cfg.max_parallel).left_stream()
+    } else {
+        rx.buffer_unordered(cfg.max_parallel).right_stream()
+    };
 
     while let Some(err) = rx.next().await {
         stats.parallel.fetch_sub(1, Ordering::Relaxed);
+        stats.publish(name);
 
         match err {
             Ok(Err(err)) => {
@@ -128,15 +151,25 @@ async fn buffer_unordered_poller<T, M>(
         _ => (),
     }
 
-    println!(
-        "[EXIT] BufferUnorderedBatchedSync<{}>",
-        std::any::type_name::<M>()
-    );
+    stats.publish_closed(name);
+
+    println!("[EXIT] {}<{}>", receiver_name, name);
 }
 
 pub struct BufferUnorderedBatchedSync<M: Message> {
     tx: mpsc::Sender<M>,
     stats: Arc<BufferUnorderedBatchedStats>,
+    stats_rx: watch::Receiver<ReceiverStats>,
+}
+
+impl<M: Message> BufferUnorderedBatchedSync<M> {
+    /// Returns a stream of `ReceiverStats` snapshots, published after each
+    /// dequeue, batch dispatch, and batch completion, for building dashboards
+    /// or autoscaling triggers without busy-polling `stats()`. Enqueueing a
+    /// message does not publish a snapshot.
+    pub fn stats_stream(&self) -> impl Stream<Item = ReceiverStats> {
+        stats_stream(self.stats_rx.clone())
+    }
 }
 
 impl<T, M> ReceiverSubscriberBuilder<M, T> for BufferUnorderedBatchedSync<M>
@@ -185,29 +218,7 @@ impl<M: Message> ReceiverTrait for BufferUnorderedBatchedSync<M> {
     }
 
     fn stats(&self) -> ReceiverStats {
-        ReceiverStats {
-            name: std::any::type_name::<M>().into(),
-            fields: vec![
-                ("buffer".into(), self.stats.buffer.load(Ordering::SeqCst)),
-                (
-                    "buffer_total".into(),
-                    self.stats.buffer_total.load(Ordering::SeqCst),
-                ),
-                (
-                    "parallel".into(),
-                    self.stats.parallel.load(Ordering::SeqCst),
-                ),
-                (
-                    "parallel_total".into(),
-                    self.stats.parallel_total.load(Ordering::SeqCst),
-                ),
-                ("batch".into(), self.stats.batch.load(Ordering::SeqCst)),
-                (
-                    "batch_size".into(),
-                    self.stats.batch_size.load(Ordering::SeqCst),
-                ),
-            ],
-        }
+        self.stats.snapshot(std::any::type_name::<M>())
     }
 
     fn close(&self) {
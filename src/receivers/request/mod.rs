@@ -0,0 +1,27 @@
+use std::sync::atomic::AtomicU64;
+
+mod sync;
+
+pub use sync::{Envelope, RequestHandler, RequestSync, RequestSyncSubscriber};
+
+#[derive(Debug, Clone, Copy)]
+pub struct RequestConfig {
+    pub buffer_size: usize,
+    pub max_parallel: usize,
+}
+
+impl Default for RequestConfig {
+    fn default() -> Self {
+        Self {
+            buffer_size: 1024,
+            max_parallel: 4,
+        }
+    }
+}
+
+pub struct RequestStats {
+    pub buffer: AtomicU64,
+    pub buffer_total: AtomicU64,
+    pub parallel: AtomicU64,
+    pub parallel_total: AtomicU64,
+}
@@ -0,0 +1,229 @@
+use crate::{receiver::ReceiverStats, receivers::mpsc};
+use futures::{channel::oneshot, executor::block_on, Future, StreamExt};
+use std::{
+    any::TypeId,
+    marker::PhantomData,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+
+use super::{RequestConfig, RequestStats};
+use crate::{
+    builder::{ReceiverSubscriber, ReceiverSubscriberBuilder},
+    receiver::{AnyReceiver, ReceiverTrait, SendError, TypedReceiver},
+    Bus, Message, Untyped,
+};
+
+/// A handler variant that returns a typed reply `R` instead of `()`, used by
+/// [`Bus::request`] to implement request/reply messaging. Errors are boxed
+/// the same way [`Handler::handle`](crate::Handler::handle)'s are, rather
+/// than through a dedicated error type.
+pub trait RequestHandler<M: Message, R: Send + 'static>: Send + Sync {
+    fn handle(&self, msg: M, bus: &Bus) -> Result<R, Box<dyn std::error::Error + Send + Sync>>;
+}
+
+/// Pairs a message with the oneshot sender its reply is routed back through.
+pub struct Envelope<M: Message, R: Send + 'static> {
+    pub(crate) msg: M,
+    pub(crate) reply: oneshot::Sender<Result<R, Box<dyn std::error::Error + Send + Sync>>>,
+}
+
+impl<M: Message, R: Send + 'static> Message for Envelope<M, R> {}
+
+pub struct RequestSyncSubscriber<T, M, R>
+where
+    T: RequestHandler<M, R> + 'static,
+    M: Message,
+    R: Send + 'static,
+{
+    cfg: RequestConfig,
+    _m: PhantomData<(M, T, R)>,
+}
+
+impl<T, M, R> ReceiverSubscriber<T> for RequestSyncSubscriber<T, M, R>
+where
+    T: RequestHandler<M, R> + 'static,
+    M: Message,
+    R: Send + 'static,
+{
+    fn subscribe(
+        self,
+    ) -> (
+        Arc<dyn ReceiverTrait>,
+        Box<
+            dyn FnOnce(Untyped) -> Box<dyn FnOnce(Bus) -> Pin<Box<dyn Future<Output = ()> + Send>>>,
+        >,
+    ) {
+        let cfg = self.cfg;
+        let (tx, rx) = mpsc::channel(cfg.buffer_size);
+        let stats = Arc::new(RequestStats {
+            buffer: AtomicU64::new(0),
+            buffer_total: AtomicU64::new(cfg.buffer_size as _),
+            parallel: AtomicU64::new(0),
+            parallel_total: AtomicU64::new(cfg.max_parallel as _),
+        });
+
+        let arc = Arc::new(RequestSync::<M, R> {
+            tx,
+            stats: stats.clone(),
+        });
+
+        let poller = Box::new(move |ut| {
+            Box::new(move |bus| {
+                Box::pin(request_poller::<T, M, R>(rx, bus, ut, stats, cfg))
+                    as Pin<Box<dyn Future<Output = ()> + Send>>
+            }) as Box<dyn FnOnce(Bus) -> Pin<Box<dyn Future<Output = ()> + Send>>>
+        });
+
+        (arc, poller)
+    }
+}
+
+async fn request_poller<T, M, R>(
+    rx: mpsc::Receiver<Envelope<M, R>>,
+    bus: Bus,
+    ut: Untyped,
+    stats: Arc<RequestStats>,
+    cfg: RequestConfig,
+) where
+    T: RequestHandler<M, R> + 'static,
+    M: Message,
+    R: Send + 'static,
+{
+    let ut = ut.downcast_sync::<T>().unwrap();
+
+    let mut x = rx
+        .map(|envelope| {
+            stats.buffer.fetch_sub(1, Ordering::Relaxed);
+            stats.parallel.fetch_add(1, Ordering::Relaxed);
+
+            let bus = bus.clone();
+            let ut = ut.clone();
+
+            tokio::task::spawn_blocking(move || {
+                let Envelope { msg, reply } = envelope;
+                let result = block_on(ut.lock_read()).get_ref().handle(msg, &bus);
+
+                // The caller may have dropped its receiver; that's not our problem.
+                let _ = reply.send(result);
+            })
+        })
+        .buffer_unordered(cfg.max_parallel);
+
+    while x.next().await.is_some() {
+        stats.parallel.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    // `RequestHandler` has no teardown hook, unlike the fire-and-forget
+    // handlers: every request is already replied to (or its reply dropped)
+    // as soon as it's handled, so there's nothing left to flush on shutdown.
+    println!("[EXIT] RequestSync<{}>", std::any::type_name::<M>());
+}
+
+pub struct RequestSync<M: Message, R: Send + 'static> {
+    tx: mpsc::Sender<Envelope<M, R>>,
+    stats: Arc<RequestStats>,
+}
+
+impl<T, M, R> ReceiverSubscriberBuilder<M, T> for RequestSync<M, R>
+where
+    T: RequestHandler<M, R> + 'static,
+    M: Message,
+    R: Send + 'static,
+{
+    type Entry = RequestSyncSubscriber<T, M, R>;
+    type Config = RequestConfig;
+
+    fn build(cfg: Self::Config) -> Self::Entry {
+        RequestSyncSubscriber {
+            cfg,
+            _m: Default::default(),
+        }
+    }
+}
+
+impl<M: Message, R: Send + 'static> TypedReceiver<Envelope<M, R>> for RequestSync<M, R> {
+    fn poll_ready(&self, ctx: &mut Context<'_>) -> Poll<()> {
+        match self.tx.poll_ready(ctx) {
+            Poll::Ready(_) => Poll::Ready(()),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn try_send(&self, m: Envelope<M, R>) -> Result<(), SendError<Envelope<M, R>>> {
+        match self.tx.try_send(m) {
+            Ok(_) => {
+                self.stats.buffer.fetch_add(1, Ordering::Relaxed);
+
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl<M: Message, R: Send + 'static> ReceiverTrait for RequestSync<M, R> {
+    fn typed(&self) -> AnyReceiver<'_> {
+        AnyReceiver::new(self)
+    }
+
+    fn type_id(&self) -> TypeId {
+        TypeId::of::<RequestSync<M, R>>()
+    }
+
+    fn stats(&self) -> ReceiverStats {
+        ReceiverStats {
+            name: std::any::type_name::<M>().into(),
+            fields: vec![
+                ("buffer".into(), self.stats.buffer.load(Ordering::SeqCst)),
+                (
+                    "buffer_total".into(),
+                    self.stats.buffer_total.load(Ordering::SeqCst),
+                ),
+                (
+                    "parallel".into(),
+                    self.stats.parallel.load(Ordering::SeqCst),
+                ),
+                (
+                    "parallel_total".into(),
+                    self.stats.parallel_total.load(Ordering::SeqCst),
+                ),
+            ],
+        }
+    }
+
+    fn close(&self) {
+        self.tx.close();
+    }
+
+    fn sync(&self) {
+        self.tx.flush();
+    }
+
+    fn poll_synchronized(&self, _ctx: &mut Context<'_>) -> Poll<()> {
+        Poll::Ready(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Mirrors the `let _ = reply.send(result);` line in the task spawned by
+    /// `request_poller`: if the caller already dropped its `Bus::request`
+    /// future (and with it, the oneshot receiver) before the handler
+    /// finished, replying must fail silently rather than panic the poller.
+    #[test]
+    fn reply_send_after_receiver_dropped_does_not_panic() {
+        let (reply, rx) =
+            oneshot::channel::<Result<u32, Box<dyn std::error::Error + Send + Sync>>>();
+        drop(rx);
+
+        let result: Result<u32, Box<dyn std::error::Error + Send + Sync>> = Ok(42);
+        let _ = reply.send(result);
+    }
+}
@@ -0,0 +1,74 @@
+use futures::Stream;
+use tokio::sync::watch;
+
+use crate::receiver::ReceiverStats;
+
+enum State {
+    Initial(watch::Receiver<ReceiverStats>),
+    Waiting(watch::Receiver<ReceiverStats>),
+}
+
+/// Turns a stats watch channel into a `Stream` that yields the current
+/// snapshot immediately, then the latest one after each subsequent change, so
+/// callers can build dashboards or autoscaling triggers without polling.
+/// Snapshots published between two polls are coalesced: only the most recent
+/// one is yielded.
+pub(crate) fn stats_stream(rx: watch::Receiver<ReceiverStats>) -> impl Stream<Item = ReceiverStats> {
+    futures::stream::unfold(State::Initial(rx), |state| async move {
+        match state {
+            State::Initial(rx) => {
+                let val = rx.borrow().clone();
+                Some((val, State::Waiting(rx)))
+            }
+            State::Waiting(mut rx) => {
+                if rx.changed().await.is_ok() {
+                    let val = rx.borrow().clone();
+                    Some((val, State::Waiting(rx)))
+                } else {
+                    None
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+
+    /// The stream must yield the current snapshot immediately (without
+    /// waiting for a change first), then a fresh snapshot on every
+    /// subsequent `publish`/`publish_closed` call, and finish once the
+    /// sender is dropped.
+    #[tokio::test]
+    async fn yields_initial_value_then_each_published_update() {
+        let initial = ReceiverStats {
+            name: "m".into(),
+            fields: vec![("buffer".into(), 0)],
+        };
+        let (tx, rx) = watch::channel(initial.clone());
+        let mut stream = Box::pin(stats_stream(rx));
+
+        assert_eq!(stream.next().await, Some(initial));
+
+        let dequeued = ReceiverStats {
+            name: "m".into(),
+            fields: vec![("buffer".into(), 1)],
+        };
+        tx.send(dequeued.clone()).unwrap();
+        assert_eq!(stream.next().await, Some(dequeued));
+
+        // `publish_closed` sends a zeroed snapshot on shutdown; callers rely
+        // on seeing it rather than the stream just going silent.
+        let closed = ReceiverStats {
+            name: "m".into(),
+            fields: vec![("buffer".into(), 0)],
+        };
+        tx.send(closed.clone()).unwrap();
+        assert_eq!(stream.next().await, Some(closed));
+
+        drop(tx);
+        assert_eq!(stream.next().await, None);
+    }
+}